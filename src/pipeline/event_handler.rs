@@ -1,5 +1,8 @@
-use crate::geometry::{ContactEvent, ContactPair, IntersectionEvent};
-use crossbeam::channel::Sender;
+use crate::geometry::{ColliderHandle, ContactEvent, ContactPair, IntersectionEvent};
+use crate::math::{Real, Vector};
+use std::collections::HashMap;
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
 
 bitflags::bitflags! {
     #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -9,6 +12,8 @@ bitflags::bitflags! {
         const INTERSECTION_EVENTS = 0b0001;
         /// If set, Rapier will call `PhysicsHooks::handle_contact_event` whenever relevant for this collider.
         const CONTACT_EVENTS = 0b0010;
+        /// If set, Rapier will call `EventHandler::handle_contact_force_event` whenever relevant for this collider.
+        const CONTACT_FORCE_EVENTS = 0b0100;
     }
 }
 
@@ -18,6 +23,101 @@ impl Default for ActiveEvents {
     }
 }
 
+/// An event emitted when the total contact force between two colliders reaches or exceeds either
+/// collider's `contact_force_event_threshold`.
+///
+/// The force is computed at the end of a substep by summing the impulses applied at every contact
+/// point of the manifold and dividing by the substep's `dt`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct ContactForceEvent {
+    /// The first collider involved in the contact.
+    pub collider1: ColliderHandle,
+    /// The second collider involved in the contact.
+    pub collider2: ColliderHandle,
+    /// The sum of the force applied at each contact point of the manifold.
+    pub total_force: Vector<Real>,
+    /// The magnitude of `Self::total_force`.
+    pub total_force_magnitude: Real,
+    /// Among all the contact points of the manifold, this is the direction, projected onto the
+    /// contact normal, of the contact point with the largest force magnitude.
+    pub max_force_direction: Vector<Real>,
+    /// The magnitude of the largest force at a single contact point of the manifold.
+    pub max_force_magnitude: Real,
+}
+
+/// Accumulates, per manifold, the impulse applied at every contact point during a substep's solve.
+///
+/// The solver pushes one of these per manifold of a contact pair as it resolves contacts; at the
+/// end of the substep [`send_contact_force_event`] consumes them to decide whether a
+/// [`ContactForceEvent`] should fire.
+pub struct ManifoldContactImpulses<'a> {
+    /// The contact normal shared by every point of the manifold.
+    pub normal: Vector<Real>,
+    /// The normal impulse accumulated at each contact point of the manifold during the substep.
+    pub point_impulses: &'a [Real],
+}
+
+/// Converts the impulses accumulated over a substep into a [`ContactForceEvent`] and forwards it
+/// to `event_handler`, if the resulting force meets or exceeds either collider's
+/// `contact_force_event_threshold`.
+///
+/// This is called by the solver at the end of a substep, once per contact pair whose colliders
+/// have `ActiveEvents::CONTACT_FORCE_EVENTS` set. `dt` is the substep's timestep, used to convert
+/// the summed impulses into forces. The two thresholds are each collider's
+/// `contact_force_event_threshold`, read by the caller off the `Collider`.
+pub fn send_contact_force_event<'a>(
+    event_handler: &dyn EventHandler,
+    dt: Real,
+    collider1: ColliderHandle,
+    collider2: ColliderHandle,
+    contact_force_event_threshold1: Real,
+    contact_force_event_threshold2: Real,
+    contact_pair: &ContactPair,
+    manifolds: impl IntoIterator<Item = ManifoldContactImpulses<'a>>,
+) {
+    if dt <= 0.0 {
+        return;
+    }
+
+    let mut total_force = Vector::zeros();
+    let mut max_force_magnitude: Real = 0.0;
+    let mut max_force_direction = Vector::zeros();
+
+    for manifold in manifolds {
+        let mut manifold_impulse: Real = 0.0;
+
+        for &point_impulse in manifold.point_impulses {
+            manifold_impulse += point_impulse;
+
+            let point_force_magnitude = point_impulse / dt;
+            if point_force_magnitude > max_force_magnitude {
+                max_force_magnitude = point_force_magnitude;
+                max_force_direction = manifold.normal;
+            }
+        }
+
+        total_force += manifold.normal * (manifold_impulse / dt);
+    }
+
+    let total_force_magnitude = total_force.norm();
+    let threshold = contact_force_event_threshold1.min(contact_force_event_threshold2);
+
+    if total_force_magnitude >= threshold {
+        event_handler.handle_contact_force_event(
+            ContactForceEvent {
+                collider1,
+                collider2,
+                total_force,
+                total_force_magnitude,
+                max_force_direction,
+                max_force_magnitude,
+            },
+            contact_pair,
+        );
+    }
+}
+
 /// Trait implemented by structures responsible for handling events generated by the physics engine.
 ///
 /// Implementors of this trait will typically collect these events for future processing.
@@ -31,6 +131,11 @@ pub trait EventHandler: Send + Sync {
     /// A contact event is emitted when two collider start or stop touching, independently from the
     /// number of contact points involved.
     fn handle_contact_event(&self, event: ContactEvent, contact_pair: &ContactPair);
+    /// Handle a contact force event.
+    ///
+    /// A contact force event is emitted when the total force applied by a contact pair reaches or
+    /// exceeds the force threshold configured on either collider. Does nothing by default.
+    fn handle_contact_force_event(&self, _event: ContactForceEvent, _contact_pair: &ContactPair) {}
 }
 
 impl EventHandler for () {
@@ -38,31 +143,576 @@ impl EventHandler for () {
     fn handle_contact_event(&self, _event: ContactEvent, _contact_pair: &ContactPair) {}
 }
 
-/// A physics event handler that collects events into a crossbeam channel.
-pub struct ChannelEventCollector {
-    intersection_event_sender: Sender<IntersectionEvent>,
-    contact_event_sender: Sender<ContactEvent>,
+/// A physics event handler that dispatches events to any number of closures registered with it.
+///
+/// Unlike implementing [`EventHandler`] directly, which forces a single handler object to own
+/// all of the event-handling logic, this registry lets independent parts of an application each
+/// register their own closure with [`Self::on_intersection`], [`Self::on_contact`], and
+/// [`Self::on_contact_force`]. Every registered closure is invoked, in registration order,
+/// whenever the corresponding event fires.
+#[derive(Default)]
+pub struct HandlerRegistry {
+    intersection_handlers: Vec<Box<dyn Fn(IntersectionEvent) + Send + Sync>>,
+    contact_handlers: Vec<Box<dyn Fn(ContactEvent, &ContactPair) + Send + Sync>>,
+    contact_force_handlers: Vec<Box<dyn Fn(ContactForceEvent, &ContactPair) + Send + Sync>>,
+}
+
+impl HandlerRegistry {
+    /// Creates a new, empty handler registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a closure to be called whenever an intersection event is generated.
+    pub fn on_intersection(
+        mut self,
+        handler: impl Fn(IntersectionEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.intersection_handlers.push(Box::new(handler));
+        self
+    }
+
+    /// Registers a closure to be called whenever a contact event is generated.
+    pub fn on_contact(
+        mut self,
+        handler: impl Fn(ContactEvent, &ContactPair) + Send + Sync + 'static,
+    ) -> Self {
+        self.contact_handlers.push(Box::new(handler));
+        self
+    }
+
+    /// Registers a closure to be called whenever a contact force event is generated.
+    pub fn on_contact_force(
+        mut self,
+        handler: impl Fn(ContactForceEvent, &ContactPair) + Send + Sync + 'static,
+    ) -> Self {
+        self.contact_force_handlers.push(Box::new(handler));
+        self
+    }
 }
 
-impl ChannelEventCollector {
-    /// Initialize a new physics event handler from crossbeam channel senders.
+impl EventHandler for HandlerRegistry {
+    fn handle_intersection_event(&self, event: IntersectionEvent) {
+        for handler in &self.intersection_handlers {
+            handler(event);
+        }
+    }
+
+    fn handle_contact_event(&self, event: ContactEvent, contact_pair: &ContactPair) {
+        for handler in &self.contact_handlers {
+            handler(event, contact_pair);
+        }
+    }
+
+    fn handle_contact_force_event(&self, event: ContactForceEvent, contact_pair: &ContactPair) {
+        for handler in &self.contact_force_handlers {
+            handler(event, contact_pair);
+        }
+    }
+}
+
+/// Abstracts over the "send" half of a channel, so that [`ChannelEventCollector`] isn't tied to
+/// any particular channel crate.
+///
+/// Implemented for [`crossbeam::channel::Sender`](crossbeam::channel::Sender) (behind the
+/// `crossbeam-channel` feature, enabled by default), [`std::sync::mpsc::Sender`], and
+/// [`flume::Sender`](flume::Sender) (behind the `flume` feature).
+pub trait EventSender<T>: Send + Sync {
+    /// Sends `event` down the channel, silently dropping it if the receiving end has disconnected.
+    fn send_event(&self, event: T);
+}
+
+#[cfg(feature = "crossbeam-channel")]
+impl<T: Send> EventSender<T> for crossbeam::channel::Sender<T> {
+    fn send_event(&self, event: T) {
+        let _ = self.send(event);
+    }
+}
+
+impl<T: Send> EventSender<T> for std::sync::mpsc::Sender<T> {
+    fn send_event(&self, event: T) {
+        let _ = self.send(event);
+    }
+}
+
+#[cfg(feature = "flume")]
+impl<T: Send> EventSender<T> for flume::Sender<T> {
+    fn send_event(&self, event: T) {
+        let _ = self.send(event);
+    }
+}
+
+/// A physics event handler that collects events into a channel.
+///
+/// Generic over the channel backend through [`EventSender`]: use
+/// [`crossbeam::channel::Sender`](crossbeam::channel::Sender) (the default, behind the
+/// `crossbeam-channel` feature), [`std::sync::mpsc::Sender`], or
+/// [`flume::Sender`](flume::Sender) (behind the `flume` feature).
+pub struct ChannelEventCollector<IS, CS, FS> {
+    intersection_event_sender: IS,
+    contact_event_sender: CS,
+    contact_force_event_sender: FS,
+}
+
+impl<IS, CS, FS> ChannelEventCollector<IS, CS, FS>
+where
+    IS: EventSender<IntersectionEvent>,
+    CS: EventSender<ContactEvent>,
+    FS: EventSender<ContactForceEvent>,
+{
+    /// Initialize a new physics event handler from channel senders.
     pub fn new(
-        intersection_event_sender: Sender<IntersectionEvent>,
-        contact_event_sender: Sender<ContactEvent>,
+        intersection_event_sender: IS,
+        contact_event_sender: CS,
+        contact_force_event_sender: FS,
     ) -> Self {
         Self {
             intersection_event_sender,
             contact_event_sender,
+            contact_force_event_sender,
         }
     }
 }
 
-impl EventHandler for ChannelEventCollector {
+impl<IS, CS, FS> EventHandler for ChannelEventCollector<IS, CS, FS>
+where
+    IS: EventSender<IntersectionEvent>,
+    CS: EventSender<ContactEvent>,
+    FS: EventSender<ContactForceEvent>,
+{
     fn handle_intersection_event(&self, event: IntersectionEvent) {
-        let _ = self.intersection_event_sender.send(event);
+        self.intersection_event_sender.send_event(event);
     }
 
     fn handle_contact_event(&self, event: ContactEvent, _: &ContactPair) {
-        let _ = self.contact_event_sender.send(event);
+        self.contact_event_sender.send_event(event);
+    }
+
+    fn handle_contact_force_event(&self, event: ContactForceEvent, _: &ContactPair) {
+        self.contact_force_event_sender.send_event(event);
+    }
+}
+
+/// A physics event handler wrapping another `EventHandler` to coalesce redundant contact events
+/// before forwarding them.
+///
+/// Without coalescing, a pair of colliders that start and stop touching within the same step (or
+/// the same kind of event firing more than once for a pair) forces the consumer to deduplicate
+/// `Started`/`Stopped` pairs itself. This collector instead buffers contact events per collider
+/// pair as they are generated and only forwards the net result to the wrapped handler once
+/// [`Self::flush`] is called, which should happen right after the pipeline step completes.
+///
+/// Buffering requires cloning the `ContactPair` of every event that doesn't immediately cancel
+/// out, since it has to outlive the call that produced it until the next `flush`. For pairs with
+/// large manifolds this clone is not free, and it is made whatever the wrapped handler is, even
+/// one that discards the pair (e.g. [`ChannelEventCollector`]).
+pub struct CoalescingEventCollector<H> {
+    inner: H,
+    buffer: Mutex<HashMap<(ColliderHandle, ColliderHandle), (ContactEvent, ContactPair)>>,
+}
+
+impl<H: EventHandler> CoalescingEventCollector<H> {
+    /// Wraps `inner` so that contact events are coalesced before being forwarded to it.
+    pub fn new(inner: H) -> Self {
+        Self {
+            inner,
+            buffer: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Forwards the net contact events accumulated since the last call to `flush`, then clears
+    /// the buffer.
+    ///
+    /// Call this once per step, after the physics pipeline has run.
+    pub fn flush(&self) {
+        let mut buffer = self.buffer.lock().unwrap();
+        for (event, contact_pair) in buffer.drain().map(|(_, v)| v) {
+            self.inner.handle_contact_event(event, &contact_pair);
+        }
+    }
+
+    /// Builds a key that is the same for `(collider1, collider2)` and `(collider2, collider1)`.
+    ///
+    /// `ColliderHandle` doesn't implement `Ord`, so the pair can't be canonicalized by comparing
+    /// the handles directly; instead we compare their raw index/generation parts, which are plain
+    /// integers.
+    fn ordered_key(
+        collider1: ColliderHandle,
+        collider2: ColliderHandle,
+    ) -> (ColliderHandle, ColliderHandle) {
+        if collider1.into_raw_parts() <= collider2.into_raw_parts() {
+            (collider1, collider2)
+        } else {
+            (collider2, collider1)
+        }
+    }
+
+    fn cancels_out(previous: ContactEvent, next: ContactEvent) -> bool {
+        matches!(
+            (previous, next),
+            (ContactEvent::Started(..), ContactEvent::Stopped(..))
+                | (ContactEvent::Stopped(..), ContactEvent::Started(..))
+        )
+    }
+}
+
+impl<H: EventHandler> EventHandler for CoalescingEventCollector<H> {
+    fn handle_intersection_event(&self, event: IntersectionEvent) {
+        self.inner.handle_intersection_event(event);
+    }
+
+    fn handle_contact_event(&self, event: ContactEvent, contact_pair: &ContactPair) {
+        let (collider1, collider2) = match event {
+            ContactEvent::Started(c1, c2) | ContactEvent::Stopped(c1, c2) => (c1, c2),
+        };
+        let key = Self::ordered_key(collider1, collider2);
+
+        let mut buffer = self.buffer.lock().unwrap();
+        match buffer.remove(&key) {
+            Some((previous, _)) if Self::cancels_out(previous, event) => {
+                // The pair cancels out (e.g. started then immediately stopped): drop both.
+            }
+            _ => {
+                buffer.insert(key, (event, contact_pair.clone()));
+            }
+        }
+    }
+
+    fn handle_contact_force_event(&self, event: ContactForceEvent, contact_pair: &ContactPair) {
+        self.inner.handle_contact_force_event(event, contact_pair);
+    }
+}
+
+#[derive(Default)]
+struct BufferedEvents {
+    intersection_events: Vec<IntersectionEvent>,
+    contact_events: Vec<(ContactEvent, ContactPair)>,
+    contact_force_events: Vec<(ContactForceEvent, ContactPair)>,
+}
+
+/// A physics event handler that buffers events in memory and lets consumers block until new ones
+/// arrive, instead of busy-polling for them.
+///
+/// This is meant for consumers running on a dedicated thread: call [`Self::wait_for_events`] to
+/// park the thread until an event is generated or the timeout elapses, then drain the buffers
+/// with [`Self::drain_intersection_events`] and [`Self::drain_contact_events`]. Unlike
+/// [`ChannelEventCollector`], the drained contact events come with their [`ContactPair`] attached,
+/// which means each contact event clones its pair into the buffer; for pairs with large manifolds
+/// that clone has a real cost.
+#[derive(Default)]
+pub struct BufferedEventCollector {
+    events: Mutex<BufferedEvents>,
+    condvar: Condvar,
+}
+
+impl BufferedEventCollector {
+    /// Creates a new, empty buffered event collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all the intersection events accumulated so far and appends them to `out`.
+    pub fn drain_intersection_events(&self, out: &mut Vec<IntersectionEvent>) {
+        let mut events = self.events.lock().unwrap();
+        out.append(&mut events.intersection_events);
+    }
+
+    /// Removes all the contact events accumulated so far and appends them, along with their
+    /// contact pair, to `out`.
+    pub fn drain_contact_events(&self, out: &mut Vec<(ContactEvent, ContactPair)>) {
+        let mut events = self.events.lock().unwrap();
+        out.append(&mut events.contact_events);
+    }
+
+    /// Removes all the contact force events accumulated so far and appends them, along with their
+    /// contact pair, to `out`.
+    pub fn drain_contact_force_events(&self, out: &mut Vec<(ContactForceEvent, ContactPair)>) {
+        let mut events = self.events.lock().unwrap();
+        out.append(&mut events.contact_force_events);
+    }
+
+    /// Blocks the current thread until a new event is generated, or `timeout` elapses.
+    ///
+    /// Returns immediately if events that haven't been drained yet are already buffered.
+    pub fn wait_for_events(&self, timeout: Duration) {
+        let events = self.events.lock().unwrap();
+        let _ = self.condvar.wait_timeout_while(events, timeout, |events| {
+            events.intersection_events.is_empty()
+                && events.contact_events.is_empty()
+                && events.contact_force_events.is_empty()
+        });
+    }
+}
+
+impl EventHandler for BufferedEventCollector {
+    fn handle_intersection_event(&self, event: IntersectionEvent) {
+        let mut events = self.events.lock().unwrap();
+        events.intersection_events.push(event);
+        self.condvar.notify_all();
+    }
+
+    fn handle_contact_event(&self, event: ContactEvent, contact_pair: &ContactPair) {
+        let mut events = self.events.lock().unwrap();
+        events.contact_events.push((event, contact_pair.clone()));
+        self.condvar.notify_all();
+    }
+
+    fn handle_contact_force_event(&self, event: ContactForceEvent, contact_pair: &ContactPair) {
+        let mut events = self.events.lock().unwrap();
+        events
+            .contact_force_events
+            .push((event, contact_pair.clone()));
+        self.condvar.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    fn handle(id: u32) -> ColliderHandle {
+        ColliderHandle::from_raw_parts(id, 0)
+    }
+
+    fn contact_pair(collider1: ColliderHandle, collider2: ColliderHandle) -> ContactPair {
+        ContactPair::new(collider1, collider2)
+    }
+
+    #[test]
+    fn send_contact_force_event_computes_total_and_max_force() {
+        let events = Mutex::new(Vec::new());
+        let handler = HandlerRegistry::new()
+            .on_contact_force(|event, _pair| events.lock().unwrap().push(event));
+        let pair = contact_pair(handle(0), handle(1));
+
+        send_contact_force_event(
+            &handler,
+            1.0,
+            handle(0),
+            handle(1),
+            0.0,
+            0.0,
+            &pair,
+            vec![
+                ManifoldContactImpulses {
+                    normal: Vector::x(),
+                    point_impulses: &[1.0, 2.0],
+                },
+                ManifoldContactImpulses {
+                    normal: Vector::y(),
+                    point_impulses: &[3.0],
+                },
+            ],
+        );
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let event = events[0];
+        assert_eq!(event.total_force, Vector::x() * 3.0 + Vector::y() * 3.0);
+        assert_eq!(event.total_force_magnitude, event.total_force.norm());
+        assert_eq!(event.max_force_direction, Vector::y());
+        assert_eq!(event.max_force_magnitude, 3.0);
+    }
+
+    #[test]
+    fn send_contact_force_event_does_nothing_below_threshold() {
+        let events = Mutex::new(Vec::new());
+        let handler = HandlerRegistry::new()
+            .on_contact_force(|event, _pair| events.lock().unwrap().push(event));
+        let pair = contact_pair(handle(0), handle(1));
+
+        send_contact_force_event(
+            &handler,
+            1.0,
+            handle(0),
+            handle(1),
+            100.0,
+            100.0,
+            &pair,
+            vec![ManifoldContactImpulses {
+                normal: Vector::x(),
+                point_impulses: &[1.0],
+            }],
+        );
+
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn handler_registry_fans_out_intersection_events_in_order() {
+        let calls = Mutex::new(Vec::new());
+        let registry = HandlerRegistry::new()
+            .on_intersection(|_event| calls.lock().unwrap().push(1))
+            .on_intersection(|_event| calls.lock().unwrap().push(2))
+            .on_intersection(|_event| calls.lock().unwrap().push(3));
+
+        registry.handle_intersection_event(IntersectionEvent {
+            collider1: handle(0),
+            collider2: handle(1),
+            intersecting: true,
+        });
+
+        assert_eq!(*calls.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn handler_registry_fans_out_contact_events_in_order() {
+        let calls = Mutex::new(Vec::new());
+        let registry = HandlerRegistry::new()
+            .on_contact(|_event, _pair| calls.lock().unwrap().push(1))
+            .on_contact(|_event, _pair| calls.lock().unwrap().push(2));
+
+        let pair = contact_pair(handle(0), handle(1));
+        registry.handle_contact_event(ContactEvent::Started(handle(0), handle(1)), &pair);
+
+        assert_eq!(*calls.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn channel_event_collector_forwards_events_to_the_senders() {
+        let (intersection_tx, intersection_rx) = std::sync::mpsc::channel();
+        let (contact_tx, contact_rx) = std::sync::mpsc::channel();
+        let (contact_force_tx, contact_force_rx) = std::sync::mpsc::channel();
+        let collector = ChannelEventCollector::new(intersection_tx, contact_tx, contact_force_tx);
+
+        let intersection_event = IntersectionEvent {
+            collider1: handle(0),
+            collider2: handle(1),
+            intersecting: true,
+        };
+        collector.handle_intersection_event(intersection_event);
+        assert_eq!(intersection_rx.recv().unwrap(), intersection_event);
+
+        let pair = contact_pair(handle(0), handle(1));
+        collector.handle_contact_event(ContactEvent::Started(handle(0), handle(1)), &pair);
+        assert_eq!(
+            contact_rx.recv().unwrap(),
+            ContactEvent::Started(handle(0), handle(1))
+        );
+
+        let force_event = ContactForceEvent {
+            collider1: handle(0),
+            collider2: handle(1),
+            total_force: Vector::zeros(),
+            total_force_magnitude: 0.0,
+            max_force_direction: Vector::zeros(),
+            max_force_magnitude: 0.0,
+        };
+        collector.handle_contact_force_event(force_event, &pair);
+        assert_eq!(contact_force_rx.recv().unwrap(), force_event);
+    }
+
+    #[test]
+    fn handler_registry_forwards_contact_force_events() {
+        let calls = AtomicUsize::new(0);
+        let registry = HandlerRegistry::new().on_contact_force(|_event, _pair| {
+            calls.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let pair = contact_pair(handle(0), handle(1));
+        registry.handle_contact_force_event(
+            ContactForceEvent {
+                collider1: handle(0),
+                collider2: handle(1),
+                total_force: Vector::zeros(),
+                total_force_magnitude: 0.0,
+                max_force_direction: Vector::zeros(),
+                max_force_magnitude: 0.0,
+            },
+            &pair,
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[derive(Default)]
+    struct RecordingHandler {
+        contact_events: Mutex<Vec<ContactEvent>>,
+    }
+
+    impl EventHandler for RecordingHandler {
+        fn handle_intersection_event(&self, _event: IntersectionEvent) {}
+
+        fn handle_contact_event(&self, event: ContactEvent, _contact_pair: &ContactPair) {
+            self.contact_events.lock().unwrap().push(event);
+        }
+    }
+
+    #[test]
+    fn coalescing_cancels_started_then_stopped() {
+        let collector = CoalescingEventCollector::new(RecordingHandler::default());
+        let pair = contact_pair(handle(0), handle(1));
+
+        collector.handle_contact_event(ContactEvent::Started(handle(0), handle(1)), &pair);
+        collector.handle_contact_event(ContactEvent::Stopped(handle(1), handle(0)), &pair);
+        collector.flush();
+
+        assert!(collector.inner.contact_events.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn coalescing_collapses_redundant_same_kind_events() {
+        let collector = CoalescingEventCollector::new(RecordingHandler::default());
+        let pair = contact_pair(handle(0), handle(1));
+
+        collector.handle_contact_event(ContactEvent::Started(handle(0), handle(1)), &pair);
+        collector.handle_contact_event(ContactEvent::Started(handle(1), handle(0)), &pair);
+        collector.flush();
+
+        assert_eq!(collector.inner.contact_events.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn buffered_collector_drains_contact_force_events() {
+        let collector = BufferedEventCollector::new();
+        let pair = contact_pair(handle(0), handle(1));
+
+        collector.handle_contact_force_event(
+            ContactForceEvent {
+                collider1: handle(0),
+                collider2: handle(1),
+                total_force: Vector::zeros(),
+                total_force_magnitude: 0.0,
+                max_force_direction: Vector::zeros(),
+                max_force_magnitude: 0.0,
+            },
+            &pair,
+        );
+
+        let mut drained = Vec::new();
+        collector.drain_contact_force_events(&mut drained);
+        assert_eq!(drained.len(), 1);
+
+        let mut drained_again = Vec::new();
+        collector.drain_contact_force_events(&mut drained_again);
+        assert!(drained_again.is_empty());
+    }
+
+    #[test]
+    fn buffered_collector_wait_for_events_returns_once_event_is_pushed() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let collector = Arc::new(BufferedEventCollector::new());
+        let producer = Arc::clone(&collector);
+
+        let handle_thread = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            producer.handle_intersection_event(IntersectionEvent {
+                collider1: handle(0),
+                collider2: handle(1),
+                intersecting: true,
+            });
+        });
+
+        collector.wait_for_events(Duration::from_secs(5));
+
+        let mut drained = Vec::new();
+        collector.drain_intersection_events(&mut drained);
+        assert_eq!(drained.len(), 1);
+
+        handle_thread.join().unwrap();
     }
 }